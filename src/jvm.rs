@@ -1,13 +1,15 @@
 use jni_sys::{
-    JavaVM, JavaVMInitArgs, JavaVMOption, JNI_CreateJavaVM, JNI_ERR, JNI_EDETACHED, JNI_EVERSION,
-    JNI_ENOMEM, JNI_EEXIST, JNI_EINVAL, JNI_FALSE, JNI_OK, JNI_VERSION_1_8, JNIEnv, jboolean, jbyte,
-    jchar, jint, jdouble, jfloat, jlong, jobject, jshort, jvalue
+    JavaVM, JavaVMInitArgs, JavaVMOption, JNINativeMethod, JNI_CreateJavaVM, JNI_GetCreatedJavaVMs,
+    JNI_ERR, JNI_EDETACHED, JNI_EVERSION, JNI_ENOMEM, JNI_EEXIST, JNI_EINVAL, JNI_FALSE, JNI_OK,
+    JNI_TRUE, JNI_VERSION_1_6, JNI_VERSION_1_8, JNI_VERSION_9, JNI_VERSION_10, JNIEnv, jboolean,
+    jbyte, jchar, jdouble, jfloat, jint, jlong, jobject, jshort, jsize, jstring, jvalue
 };
 use jvm_attachment::JvmAttachment;
 use jvm_class::JvmClass;
 use jvm_method::JvmMethod;
 use jvm_object::JvmObject;
-use std::ffi::CString;
+use std::ffi::{CStr, CString};
+use std::fmt;
 use std::ptr;
 use std::os::raw::c_void;
 
@@ -70,28 +72,471 @@ unsafe fn jvm_exception_occured(jni_environment: *mut JNIEnv) -> bool {
 }
 
 ///
-unsafe fn print_and_panic_on_jvm_exception(jni_environment: *mut JNIEnv) {
+pub unsafe fn print_jvm_exception(jni_environment: *mut JNIEnv) {
 
     // A JVM exception occurred.
     if jvm_exception_occured(jni_environment) {
 
         // Print the JVM exception.
         (**jni_environment).ExceptionDescribe.unwrap()(jni_environment);
-
-        // Panic.
-        panic!("An exception occurred");
     };
 }
 
+/// Converts a `jstring` to a Rust `String`, releasing the JVM-owned characters afterwards.
+unsafe fn string_from_jstring(jni_environment: *mut JNIEnv, jvm_string: jstring) -> Option<String> {
+
+    if jvm_string.is_null() {
+        return None;
+    }
+
+    let chars = (**jni_environment).GetStringUTFChars.unwrap()(jni_environment, jvm_string, ptr::null_mut());
+    let string = CStr::from_ptr(chars).to_string_lossy().into_owned();
+
+    (**jni_environment).ReleaseStringUTFChars.unwrap()(jni_environment, jvm_string, chars);
+
+    Some(string)
+}
+
+/// If a JVM exception is pending, clears it and captures it as a `JvmError::JavaException`.
+unsafe fn take_jvm_exception(jni_environment: *mut JNIEnv) -> Option<JvmError> {
+
+    let jvm_throwable = (**jni_environment).ExceptionOccurred.unwrap()(jni_environment);
+
+    if jvm_throwable.is_null() {
+        return None;
+    }
+
+    // Clear the exception so that further JNI calls (made while building the `JvmError`) succeed.
+    (**jni_environment).ExceptionClear.unwrap()(jni_environment);
+
+    let throwable_class = (**jni_environment).GetObjectClass.unwrap()(jni_environment, jvm_throwable);
+
+    let class_class = (**jni_environment).FindClass.unwrap()(
+        jni_environment, b"java/lang/Class\0".as_ptr() as *const i8
+    );
+
+    let get_name_method = (**jni_environment).GetMethodID.unwrap()(
+        jni_environment, class_class, b"getName\0".as_ptr() as *const i8, b"()Ljava/lang/String;\0".as_ptr() as *const i8
+    );
+
+    let get_message_method = (**jni_environment).GetMethodID.unwrap()(
+        jni_environment, throwable_class, b"getMessage\0".as_ptr() as *const i8, b"()Ljava/lang/String;\0".as_ptr() as *const i8
+    );
+
+    let class_name_jstring = (**jni_environment).CallObjectMethodA.unwrap()(
+        jni_environment, throwable_class, get_name_method, ptr::null()
+    ) as jstring;
+
+    let message_jstring = (**jni_environment).CallObjectMethodA.unwrap()(
+        jni_environment, jvm_throwable, get_message_method, ptr::null()
+    ) as jstring;
+
+    let class_name = string_from_jstring(jni_environment, class_name_jstring).unwrap_or_default();
+    let message = string_from_jstring(jni_environment, message_jstring);
+
+    Some(JvmError::JavaException { class_name, message })
+}
+
+/// Returns `Err` if a JVM exception is pending, capturing and clearing it in the process.
+unsafe fn check_jvm_exception(jni_environment: *mut JNIEnv) -> Result<(), JvmError> {
+
+    match take_jvm_exception(jni_environment) {
+        Some(jvm_error) => Err(jvm_error),
+        None => Ok(()),
+    }
+}
+
+/// Throws a Java exception for the given `JvmError`, picking `NullPointerException` for a missing
+/// class or method, `IllegalArgumentException` for an `InvalidArgList`, and `RuntimeException`
+/// for a propagated `JavaException`.
 ///
-pub unsafe fn print_jvm_exception(jni_environment: *mut JNIEnv) {
+/// Intended for `extern "C"` callbacks registered via `Jvm::register_natives`: translate a
+/// returned `Err` with this function, then return to the JVM rather than continuing execution.
+pub unsafe fn throw_jvm_error(jni_environment: *mut JNIEnv, jvm_error: &JvmError) {
 
-    // A JVM exception occurred.
-    if jvm_exception_occured(jni_environment) {
+    // Do not clobber an exception that is already pending.
+    if (**jni_environment).ExceptionCheck.unwrap()(jni_environment) != 0 {
+        return;
+    }
 
-        // Print the JVM exception.
-        (**jni_environment).ExceptionDescribe.unwrap()(jni_environment);
+    let exception_class_name = match jvm_error {
+        JvmError::ClassNotFound(_) | JvmError::MethodNotFound(_) => "java/lang/NullPointerException",
+        JvmError::InvalidArgList(_) => "java/lang/IllegalArgumentException",
+        JvmError::JavaException { .. } => "java/lang/RuntimeException",
     };
+
+    let exception_class_name_cstring = CString::new(exception_class_name).unwrap();
+
+    let exception_class = (**jni_environment).FindClass.unwrap()(
+        jni_environment, exception_class_name_cstring.as_ptr()
+    );
+
+    let constructor = (**jni_environment).GetMethodID.unwrap()(
+        jni_environment,
+        exception_class,
+        b"<init>\0".as_ptr() as *const i8,
+        b"(Ljava/lang/String;)V\0".as_ptr() as *const i8
+    );
+
+    let message_cstring = CString::new(jvm_error.to_string()).unwrap();
+    let message_jstring = (**jni_environment).NewStringUTF.unwrap()(jni_environment, message_cstring.as_ptr());
+
+    let args = [jvalue { l: message_jstring }];
+
+    let exception_object = (**jni_environment).NewObjectA.unwrap()(
+        jni_environment, exception_class, constructor, args.as_ptr()
+    );
+
+    (**jni_environment).Throw.unwrap()(jni_environment, exception_object);
+}
+
+// =================================================================================================
+
+/// Describes why a call into the JVM failed.
+#[derive(Debug)]
+pub enum JvmError {
+
+    /// `FindClass` could not resolve the named class.
+    ClassNotFound(String),
+
+    /// The `JvmValue`s passed to a call method do not match the target method's signature.
+    InvalidArgList(String),
+
+    /// A Java exception was thrown and propagated back to Rust.
+    JavaException {
+
+        /// The fully qualified name of the exception's class.
+        class_name: String,
+
+        /// The exception's message, as returned by `getMessage()`.
+        message: Option<String>,
+    },
+
+    /// The requested method or constructor could not be resolved.
+    MethodNotFound(String),
+}
+
+impl fmt::Display for JvmError {
+
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            JvmError::ClassNotFound(jvm_class_name) =>
+                write!(formatter, "class not found: {}", jvm_class_name),
+            JvmError::InvalidArgList(message) =>
+                write!(formatter, "invalid argument list: {}", message),
+            JvmError::JavaException { class_name, message: Some(message) } =>
+                write!(formatter, "{}: {}", class_name, message),
+            JvmError::JavaException { class_name, message: None } =>
+                write!(formatter, "{}", class_name),
+            JvmError::MethodNotFound(jvm_method_signature) =>
+                write!(formatter, "method not found: {}", jvm_method_signature),
+        }
+    }
+}
+
+impl std::error::Error for JvmError {
+}
+
+// =================================================================================================
+
+/// Selects the `JNI_VERSION_*` a `Jvm` is initialized with.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum JNIVersion {
+
+    /// JNI 1.6.
+    V1_6,
+
+    /// JNI 1.8.
+    V1_8,
+
+    /// JNI 9.
+    V9,
+
+    /// JNI 10.
+    V10,
+}
+
+impl JNIVersion {
+
+    fn as_jint(self) -> jint {
+        match self {
+            JNIVersion::V1_6 => JNI_VERSION_1_6,
+            JNIVersion::V1_8 => JNI_VERSION_1_8,
+            JNIVersion::V9 => JNI_VERSION_9,
+            JNIVersion::V10 => JNI_VERSION_10,
+        }
+    }
+}
+
+// =================================================================================================
+
+/// Builds the `JavaVMInitArgs` a `Jvm` is created with, giving control over JNI version
+/// negotiation and unrecognized-option handling that `Jvm::new` hard-codes.
+///
+/// # Example
+///
+/// ```
+/// use rucaja::{Jvm, JNIVersion};
+/// unsafe {
+///   Jvm::builder().version(JNIVersion::V1_8).option("-Xcheck:jni").build();
+/// }
+/// ```
+pub struct InitArgsBuilder {
+
+    /// The JNI version the JVM is initialized with.
+    jni_version: JNIVersion,
+
+    /// Whether unrecognized options are ignored instead of causing `JNI_EINVAL`.
+    ignore_unrecognized: bool,
+
+    /// The JVM option strings, in the order they were appended.
+    jvm_option_strings: Vec<String>,
+}
+
+impl InitArgsBuilder {
+
+    fn new() -> InitArgsBuilder {
+        InitArgsBuilder {
+            jni_version: JNIVersion::V1_8,
+            ignore_unrecognized: false,
+            jvm_option_strings: Vec::new(),
+        }
+    }
+
+    /// Selects the `JNIVersion` the JVM is initialized with. Defaults to `JNIVersion::V1_8`.
+    pub fn version(mut self, jni_version: JNIVersion) -> InitArgsBuilder {
+        self.jni_version = jni_version;
+        self
+    }
+
+    /// Sets whether unrecognized options (e.g. unknown `-XX` flags) are ignored instead of
+    /// causing `JNI_CreateJavaVM` to fail with `JNI_EINVAL`. Defaults to `false`.
+    pub fn ignore_unrecognized(mut self, ignore_unrecognized: bool) -> InitArgsBuilder {
+        self.ignore_unrecognized = ignore_unrecognized;
+        self
+    }
+
+    /// Appends a single JVM option string.
+    pub fn option(mut self, jvm_option_string: &str) -> InitArgsBuilder {
+        self.jvm_option_strings.push(jvm_option_string.to_string());
+        self
+    }
+
+    /// Tries to instantiate the embedded JVM with the configured arguments.
+    ///
+    /// The JNI does not allow the creation of multiple JVMs in the same process (it seems, not
+    /// even sequentially). An attempt will result in a `panic`.
+    pub unsafe fn build(self) -> Jvm {
+
+        // Wrap the JVM option string slices in a vector of `CString`s.
+        let mut jvm_option_cstrings : Vec<CString> = Vec::new();
+
+        for jvm_option_string in &self.jvm_option_strings {
+            jvm_option_cstrings.push(CString::new(jvm_option_string.as_str()).unwrap());
+        }
+
+        // Create a vector of `JavaVMOption`s, each referencing a `CString`.
+        let mut jvm_options : Vec<JavaVMOption> = Vec::new();
+
+        for jvm_option_cstring in &jvm_option_cstrings {
+
+            let jvm_option = JavaVMOption {
+                optionString: jvm_option_cstring.as_ptr() as *mut i8,
+                extraInfo: ptr::null_mut() as *mut c_void
+            };
+
+            jvm_options.push(jvm_option);
+        }
+
+        // Create the JVM arguments.
+        let jvm_arguments = JavaVMInitArgs {
+            version: self.jni_version.as_jint(),
+            options: jvm_options.as_mut_ptr(),
+            nOptions: jvm_options.len() as i32,
+            ignoreUnrecognized: if self.ignore_unrecognized { JNI_TRUE } else { JNI_FALSE }
+        };
+
+        Jvm::create(jvm_arguments)
+    }
+}
+
+// =================================================================================================
+
+/// A Rust function to be registered as a Java `native` method via `Jvm::register_natives`.
+pub struct NativeMethod {
+
+    /// The Java method name, e.g. `"doWork"`.
+    pub name: &'static str,
+
+    /// The JNI type signature of the method, e.g. `"(I)V"`.
+    pub signature: &'static str,
+
+    /// The `extern "C"` callback invoked when the JVM calls the native method.
+    pub function_pointer: *mut c_void,
+}
+
+// =================================================================================================
+
+/// A type-checked argument to a JVM method call, replacing manually-built `jvalue`s.
+pub enum JvmValue<'a> {
+
+    /// A `boolean` argument.
+    Boolean(jboolean),
+
+    /// A `byte` argument.
+    Byte(jbyte),
+
+    /// A `char` argument.
+    Char(jchar),
+
+    /// A `double` argument.
+    Double(jdouble),
+
+    /// A `float` argument.
+    Float(jfloat),
+
+    /// An `int` argument.
+    Int(jint),
+
+    /// A `long` argument.
+    Long(jlong),
+
+    /// An object (or array) argument.
+    Object(&'a JvmObject),
+
+    /// A `short` argument.
+    Short(jshort),
+}
+
+impl<'a> fmt::Debug for JvmValue<'a> {
+
+    // `JvmObject` does not implement `Debug`, so the `Object` variant is named without printing
+    // the referenced object itself.
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            JvmValue::Boolean(value) => write!(formatter, "Boolean({:?})", value),
+            JvmValue::Byte(value) => write!(formatter, "Byte({:?})", value),
+            JvmValue::Char(value) => write!(formatter, "Char({:?})", value),
+            JvmValue::Double(value) => write!(formatter, "Double({:?})", value),
+            JvmValue::Float(value) => write!(formatter, "Float({:?})", value),
+            JvmValue::Int(value) => write!(formatter, "Int({:?})", value),
+            JvmValue::Long(value) => write!(formatter, "Long({:?})", value),
+            JvmValue::Object(_) => write!(formatter, "Object(_)"),
+            JvmValue::Short(value) => write!(formatter, "Short({:?})", value),
+        }
+    }
+}
+
+impl<'a> JvmValue<'a> {
+
+    unsafe fn as_jvalue(&self) -> jvalue {
+        match self {
+            JvmValue::Boolean(value) => jvalue { z: *value },
+            JvmValue::Byte(value) => jvalue { b: *value },
+            JvmValue::Char(value) => jvalue { c: *value },
+            JvmValue::Double(value) => jvalue { d: *value },
+            JvmValue::Float(value) => jvalue { f: *value },
+            JvmValue::Int(value) => jvalue { i: *value },
+            JvmValue::Long(value) => jvalue { j: *value },
+            JvmValue::Object(jvm_object) => jvalue { l: jvm_object.jvm_ptr() },
+            JvmValue::Short(value) => jvalue { s: *value },
+        }
+    }
+
+    /// Whether this value is a valid argument for the given JNI parameter type descriptor, e.g.
+    /// `"I"` or `"Ljava/lang/String;"`.
+    fn matches_descriptor(&self, descriptor: &str) -> bool {
+        match self {
+            JvmValue::Boolean(_) => descriptor == "Z",
+            JvmValue::Byte(_) => descriptor == "B",
+            JvmValue::Char(_) => descriptor == "C",
+            JvmValue::Double(_) => descriptor == "D",
+            JvmValue::Float(_) => descriptor == "F",
+            JvmValue::Int(_) => descriptor == "I",
+            JvmValue::Long(_) => descriptor == "J",
+            JvmValue::Object(_) => descriptor.starts_with('L') || descriptor.starts_with('['),
+            JvmValue::Short(_) => descriptor == "S",
+        }
+    }
+}
+
+/// Splits a JNI method signature, e.g. `"(ILjava/lang/String;)V"`, into its parameter descriptors.
+///
+/// Fails closed with `JvmError::InvalidArgList` rather than panicking on a truncated or malformed
+/// signature (a dangling `[` or an `L` descriptor with no terminating `;`).
+fn parameter_descriptors(jvm_method_signature: &str) -> Result<Vec<&str>, JvmError> {
+
+    let malformed = || JvmError::InvalidArgList(
+        format!("malformed method signature `{}`", jvm_method_signature)
+    );
+
+    let parameters = jvm_method_signature
+        .strip_prefix('(')
+        .and_then(|rest| rest.split(')').next())
+        .ok_or_else(malformed)?;
+
+    let bytes = parameters.as_bytes();
+    let mut descriptors = Vec::new();
+    let mut index = 0;
+
+    while index < bytes.len() {
+
+        let start = index;
+
+        while bytes[index] == b'[' {
+            index += 1;
+            if index >= bytes.len() {
+                return Err(malformed());
+            }
+        }
+
+        if bytes[index] == b'L' {
+            while bytes[index] != b';' {
+                index += 1;
+                if index >= bytes.len() {
+                    return Err(malformed());
+                }
+            }
+        }
+
+        descriptors.push(&parameters[start..=index]);
+        index += 1;
+    }
+
+    Ok(descriptors)
+}
+
+/// Validates `args` against the target method's JNI signature and lowers them to `jvalue`s.
+///
+/// `jvm_method_signature` must be the same signature string the caller resolved `jvm_method`
+/// with, since `JvmMethod` itself does not cache it.
+unsafe fn jvalues_from_jvm_values(
+    jvm_method_signature: &str, args: &[JvmValue]
+) -> Result<Vec<jvalue>, JvmError> {
+
+    let descriptors = parameter_descriptors(jvm_method_signature)?;
+
+    if descriptors.len() != args.len() {
+        return Err(JvmError::InvalidArgList(format!(
+            "{} expects {} argument(s), got {}", jvm_method_signature, descriptors.len(), args.len()
+        )));
+    }
+
+    let mut jvalues = Vec::with_capacity(args.len());
+
+    for (jvm_value, descriptor) in args.iter().zip(descriptors.iter()) {
+
+        if !jvm_value.matches_descriptor(descriptor) {
+            return Err(JvmError::InvalidArgList(format!(
+                "{:?} does not match parameter descriptor `{}` of {}",
+                jvm_value, descriptor, jvm_method_signature
+            )));
+        }
+
+        jvalues.push(jvm_value.as_jvalue());
+    }
+
+    Ok(jvalues)
 }
 
 // =================================================================================================
@@ -129,33 +574,26 @@ impl Jvm {
     /// ```
     pub unsafe fn new(jvm_option_strings: &[&str]) -> Jvm {
 
-        // Wrap the JVM option string slices in a vector of `CString`s.
-        let mut jvm_option_cstrings : Vec<CString> = Vec::new();
+        let mut init_args_builder = Jvm::builder();
 
         for jvm_option_string in jvm_option_strings {
-            jvm_option_cstrings.push(CString::new(*jvm_option_string).unwrap());
+            init_args_builder = init_args_builder.option(jvm_option_string);
         }
 
-        // Create a vector of `JavaVMOption`s, each referencing a `CString`.
-        let mut jvm_options : Vec<JavaVMOption> = Vec::new();
-
-        for jvm_option_cstring in &jvm_option_cstrings {
-
-            let jvm_option = JavaVMOption {
-                optionString: jvm_option_cstring.as_ptr() as *mut i8,
-                extraInfo: ptr::null_mut() as *mut c_void
-            };
+        init_args_builder.build()
+    }
 
-            jvm_options.push(jvm_option);
-        }
+    /// Returns an `InitArgsBuilder` for configuring the JNI version, unrecognized-option policy,
+    /// and options a `Jvm` is created with.
+    pub fn builder() -> InitArgsBuilder {
+        InitArgsBuilder::new()
+    }
 
-        // Create the JVM arguments.
-        let mut jvm_arguments = JavaVMInitArgs {
-            version: JNI_VERSION_1_8,
-            options: jvm_options.as_mut_ptr(),
-            nOptions: jvm_options.len() as i32,
-            ignoreUnrecognized: JNI_FALSE
-        };
+    /// Tries to instantiate the embedded JVM with the given `JavaVMInitArgs`.
+    ///
+    /// The JNI does not allow the creation of multiple JVMs in the same process (it seems, not even
+    /// sequentially). An attempt will result in a `panic`.
+    unsafe fn create(mut jvm_arguments: JavaVMInitArgs) -> Jvm {
 
         // Initialize space for a pointer to the JNI environment.
         let mut jvm: *mut JavaVM = ptr::null_mut();
@@ -188,93 +626,511 @@ impl Jvm {
         Jvm { jvm }
     }
 
+    /// Attaches to an already-running JVM in this process, or creates one if none exists yet.
+    ///
+    /// The JNI forbids creating a second JVM in a process that already hosts one (see `new`).
+    /// This constructor instead calls `JNI_GetCreatedJavaVMs` first and reuses the existing JVM
+    /// when one is found, which makes rucaja usable when it is loaded alongside other JNI code
+    /// that may have created the JVM already.
+    ///
+    /// # Arguments
+    ///
+    /// * `jvm_option_strings` - a list of JVM option strings, used only if a JVM has to be created.
+    pub unsafe fn attach_or_create(jvm_option_strings: &[&str]) -> Jvm {
+
+        let mut jvm: *mut JavaVM = ptr::null_mut();
+        let mut created_jvm_count: jsize = 0;
+
+        let result = JNI_GetCreatedJavaVMs(&mut jvm, 1, &mut created_jvm_count);
+
+        if result == JNI_OK && created_jvm_count > 0 {
+            return Jvm { jvm };
+        }
+
+        Jvm::new(jvm_option_strings)
+    }
+
     /// Tries to call the given JVM object constructor in the given JVM class.
-    /// Currently panics if a JVM exception occurs.
     pub unsafe fn call_constructor(
-        &self, jvm_class: &JvmClass, jvm_constructor_method: &JvmMethod, args: *const jvalue
-    ) -> jobject {
+        &self, jvm_class: &JvmClass, jvm_constructor_method: &JvmMethod, jvm_method_signature: &str, args: &[JvmValue]
+    ) -> Result<jobject, JvmError> {
 
         // Attach the current native thread to the JVM.
         let jvm_attachment = JvmAttachment::new(self.jvm);
 
+        let jvalues = jvalues_from_jvm_values(jvm_method_signature, args)?;
+
         let object = (**jvm_attachment.jni_environment()).NewObjectA.unwrap()(
             jvm_attachment.jni_environment(),
             jvm_class.jvm_ptr(),
             jvm_constructor_method.jvm_ptr(),
-            args
+            jvalues.as_ptr()
         );
 
-        print_and_panic_on_jvm_exception(jvm_attachment.jni_environment());
+        check_jvm_exception(jvm_attachment.jni_environment())?;
 
-        object
+        Ok(object)
     }
 
-    // TODO: call_boolean_method()
+    /// Tries to call the given JVM instance boolean method on the given JVM object.
+    pub unsafe fn call_boolean_method(
+        &self, jvm_object: &JvmObject, jvm_method: &JvmMethod, jvm_method_signature: &str, args: &[JvmValue]
+    ) -> Result<jboolean, JvmError> {
 
-    // TODO: call_byte_method()
+        // Attach the current native thread to the JVM.
+        let jvm_attachment = JvmAttachment::new(self.jvm);
 
-    // TODO: call_char_method()
+        let jvalues = jvalues_from_jvm_values(jvm_method_signature, args)?;
 
-    // TODO: call_double_method()
+        let result = (**jvm_attachment.jni_environment()).CallBooleanMethodA.unwrap()(
+            jvm_attachment.jni_environment(),
+            jvm_object.jvm_ptr(),
+            jvm_method.jvm_ptr(),
+            jvalues.as_ptr()
+        );
 
-    // TODO: call_float_method()
+        check_jvm_exception(jvm_attachment.jni_environment())?;
 
-    // TODO: call_int_method()
+        Ok(result)
+    }
 
-    // TODO: call_long_method()
+    /// Tries to call the given JVM instance byte method on the given JVM object.
+    pub unsafe fn call_byte_method(
+        &self, jvm_object: &JvmObject, jvm_method: &JvmMethod, jvm_method_signature: &str, args: &[JvmValue]
+    ) -> Result<jbyte, JvmError> {
 
-    // TODO: call_object_method()
+        // Attach the current native thread to the JVM.
+        let jvm_attachment = JvmAttachment::new(self.jvm);
 
-    // TODO: call_short_method()
+        let jvalues = jvalues_from_jvm_values(jvm_method_signature, args)?;
 
-    // TODO: call_void_method()
+        let result = (**jvm_attachment.jni_environment()).CallByteMethodA.unwrap()(
+            jvm_attachment.jni_environment(),
+            jvm_object.jvm_ptr(),
+            jvm_method.jvm_ptr(),
+            jvalues.as_ptr()
+        );
 
+        check_jvm_exception(jvm_attachment.jni_environment())?;
 
-    // TODO: call_nonvirtual_boolean_method()
+        Ok(result)
+    }
 
-    // TODO: call_nonvirtual_byte_method()
+    /// Tries to call the given JVM instance char method on the given JVM object.
+    pub unsafe fn call_char_method(
+        &self, jvm_object: &JvmObject, jvm_method: &JvmMethod, jvm_method_signature: &str, args: &[JvmValue]
+    ) -> Result<jchar, JvmError> {
 
-    // TODO: call_nonvirtual_char_method()
+        // Attach the current native thread to the JVM.
+        let jvm_attachment = JvmAttachment::new(self.jvm);
 
-    // TODO: call_nonvirtual_double_method()
+        let jvalues = jvalues_from_jvm_values(jvm_method_signature, args)?;
 
-    // TODO: call_nonvirtual_float_method()
+        let result = (**jvm_attachment.jni_environment()).CallCharMethodA.unwrap()(
+            jvm_attachment.jni_environment(),
+            jvm_object.jvm_ptr(),
+            jvm_method.jvm_ptr(),
+            jvalues.as_ptr()
+        );
 
-    // TODO: call_nonvirtual_int_method()
+        check_jvm_exception(jvm_attachment.jni_environment())?;
 
-    // TODO: call_nonvirtual_long_method()
+        Ok(result)
+    }
 
-    // TODO: call_nonvirtual_object_method()
+    /// Tries to call the given JVM instance double method on the given JVM object.
+    pub unsafe fn call_double_method(
+        &self, jvm_object: &JvmObject, jvm_method: &JvmMethod, jvm_method_signature: &str, args: &[JvmValue]
+    ) -> Result<jdouble, JvmError> {
 
-    // TODO: call_nonvirtual_short_method()
+        // Attach the current native thread to the JVM.
+        let jvm_attachment = JvmAttachment::new(self.jvm);
 
-    // TODO: call_nonvirtual_void_method()
+        let jvalues = jvalues_from_jvm_values(jvm_method_signature, args)?;
 
+        let result = (**jvm_attachment.jni_environment()).CallDoubleMethodA.unwrap()(
+            jvm_attachment.jni_environment(),
+            jvm_object.jvm_ptr(),
+            jvm_method.jvm_ptr(),
+            jvalues.as_ptr()
+        );
 
+        check_jvm_exception(jvm_attachment.jni_environment())?;
 
-    // TODO: call_static_boolean_method()
+        Ok(result)
+    }
 
-    /// Tries to call the given JVM static boolean method in the given JVM class.
-    /// Currently panics if a JVM exception occurs.
-    pub unsafe fn call_static_boolean_method(
-        &self, jvm_class: &JvmClass, jvm_method: &JvmMethod, args: *const jvalue
-    ) -> jboolean {
+    /// Tries to call the given JVM instance float method on the given JVM object.
+    pub unsafe fn call_float_method(
+        &self, jvm_object: &JvmObject, jvm_method: &JvmMethod, jvm_method_signature: &str, args: &[JvmValue]
+    ) -> Result<jfloat, JvmError> {
 
         // Attach the current native thread to the JVM.
         let jvm_attachment = JvmAttachment::new(self.jvm);
 
-        let result = (**jvm_attachment.jni_environment()).CallStaticBooleanMethodA.unwrap()(
+        let jvalues = jvalues_from_jvm_values(jvm_method_signature, args)?;
+
+        let result = (**jvm_attachment.jni_environment()).CallFloatMethodA.unwrap()(
+            jvm_attachment.jni_environment(),
+            jvm_object.jvm_ptr(),
+            jvm_method.jvm_ptr(),
+            jvalues.as_ptr()
+        );
+
+        check_jvm_exception(jvm_attachment.jni_environment())?;
+
+        Ok(result)
+    }
+
+    /// Tries to call the given JVM instance int method on the given JVM object.
+    pub unsafe fn call_int_method(
+        &self, jvm_object: &JvmObject, jvm_method: &JvmMethod, jvm_method_signature: &str, args: &[JvmValue]
+    ) -> Result<jint, JvmError> {
+
+        // Attach the current native thread to the JVM.
+        let jvm_attachment = JvmAttachment::new(self.jvm);
+
+        let jvalues = jvalues_from_jvm_values(jvm_method_signature, args)?;
+
+        let result = (**jvm_attachment.jni_environment()).CallIntMethodA.unwrap()(
+            jvm_attachment.jni_environment(),
+            jvm_object.jvm_ptr(),
+            jvm_method.jvm_ptr(),
+            jvalues.as_ptr()
+        );
+
+        check_jvm_exception(jvm_attachment.jni_environment())?;
+
+        Ok(result)
+    }
+
+    /// Tries to call the given JVM instance long method on the given JVM object.
+    pub unsafe fn call_long_method(
+        &self, jvm_object: &JvmObject, jvm_method: &JvmMethod, jvm_method_signature: &str, args: &[JvmValue]
+    ) -> Result<jlong, JvmError> {
+
+        // Attach the current native thread to the JVM.
+        let jvm_attachment = JvmAttachment::new(self.jvm);
+
+        let jvalues = jvalues_from_jvm_values(jvm_method_signature, args)?;
+
+        let result = (**jvm_attachment.jni_environment()).CallLongMethodA.unwrap()(
+            jvm_attachment.jni_environment(),
+            jvm_object.jvm_ptr(),
+            jvm_method.jvm_ptr(),
+            jvalues.as_ptr()
+        );
+
+        check_jvm_exception(jvm_attachment.jni_environment())?;
+
+        Ok(result)
+    }
+
+    /// Tries to call the given JVM instance object method on the given JVM object.
+    pub unsafe fn call_object_method(
+        &self, jvm_object: &JvmObject, jvm_method: &JvmMethod, jvm_method_signature: &str, args: &[JvmValue]
+    ) -> Result<Option<JvmObject>, JvmError> {
+
+        // Attach the current native thread to the JVM.
+        let jvm_attachment = JvmAttachment::new(self.jvm);
+
+        let jvalues = jvalues_from_jvm_values(jvm_method_signature, args)?;
+
+        let result = (**jvm_attachment.jni_environment()).CallObjectMethodA.unwrap()(
+            jvm_attachment.jni_environment(),
+            jvm_object.jvm_ptr(),
+            jvm_method.jvm_ptr(),
+            jvalues.as_ptr()
+        );
+
+        check_jvm_exception(jvm_attachment.jni_environment())?;
+
+        Ok(JvmObject::from_jvm_ptr(self, result))
+    }
+
+    /// Tries to call the given JVM instance short method on the given JVM object.
+    pub unsafe fn call_short_method(
+        &self, jvm_object: &JvmObject, jvm_method: &JvmMethod, jvm_method_signature: &str, args: &[JvmValue]
+    ) -> Result<jshort, JvmError> {
+
+        // Attach the current native thread to the JVM.
+        let jvm_attachment = JvmAttachment::new(self.jvm);
+
+        let jvalues = jvalues_from_jvm_values(jvm_method_signature, args)?;
+
+        let result = (**jvm_attachment.jni_environment()).CallShortMethodA.unwrap()(
+            jvm_attachment.jni_environment(),
+            jvm_object.jvm_ptr(),
+            jvm_method.jvm_ptr(),
+            jvalues.as_ptr()
+        );
+
+        check_jvm_exception(jvm_attachment.jni_environment())?;
+
+        Ok(result)
+    }
+
+    /// Tries to call the given JVM instance void method on the given JVM object.
+    pub unsafe fn call_void_method(
+        &self, jvm_object: &JvmObject, jvm_method: &JvmMethod, jvm_method_signature: &str, args: &[JvmValue]
+    ) -> Result<(), JvmError> {
+
+        // Attach the current native thread to the JVM.
+        let jvm_attachment = JvmAttachment::new(self.jvm);
+
+        let jvalues = jvalues_from_jvm_values(jvm_method_signature, args)?;
+
+        (**jvm_attachment.jni_environment()).CallVoidMethodA.unwrap()(
+            jvm_attachment.jni_environment(),
+            jvm_object.jvm_ptr(),
+            jvm_method.jvm_ptr(),
+            jvalues.as_ptr()
+        );
+
+        check_jvm_exception(jvm_attachment.jni_environment())
+    }
+
+
+    /// Tries to call the given JVM instance boolean method, bypassing dynamic dispatch to invoke
+    /// the version declared in the given JVM class.
+    pub unsafe fn call_nonvirtual_boolean_method(
+        &self, jvm_object: &JvmObject, jvm_class: &JvmClass, jvm_method: &JvmMethod, jvm_method_signature: &str, args: &[JvmValue]
+    ) -> Result<jboolean, JvmError> {
+
+        // Attach the current native thread to the JVM.
+        let jvm_attachment = JvmAttachment::new(self.jvm);
+
+        let jvalues = jvalues_from_jvm_values(jvm_method_signature, args)?;
+
+        let result = (**jvm_attachment.jni_environment()).CallNonvirtualBooleanMethodA.unwrap()(
+            jvm_attachment.jni_environment(),
+            jvm_object.jvm_ptr(),
+            jvm_class.jvm_ptr(),
+            jvm_method.jvm_ptr(),
+            jvalues.as_ptr()
+        );
+
+        check_jvm_exception(jvm_attachment.jni_environment())?;
+
+        Ok(result)
+    }
+
+    /// Tries to call the given JVM instance byte method, bypassing dynamic dispatch to invoke the
+    /// version declared in the given JVM class.
+    pub unsafe fn call_nonvirtual_byte_method(
+        &self, jvm_object: &JvmObject, jvm_class: &JvmClass, jvm_method: &JvmMethod, jvm_method_signature: &str, args: &[JvmValue]
+    ) -> Result<jbyte, JvmError> {
+
+        // Attach the current native thread to the JVM.
+        let jvm_attachment = JvmAttachment::new(self.jvm);
+
+        let jvalues = jvalues_from_jvm_values(jvm_method_signature, args)?;
+
+        let result = (**jvm_attachment.jni_environment()).CallNonvirtualByteMethodA.unwrap()(
+            jvm_attachment.jni_environment(),
+            jvm_object.jvm_ptr(),
+            jvm_class.jvm_ptr(),
+            jvm_method.jvm_ptr(),
+            jvalues.as_ptr()
+        );
+
+        check_jvm_exception(jvm_attachment.jni_environment())?;
+
+        Ok(result)
+    }
+
+    /// Tries to call the given JVM instance char method, bypassing dynamic dispatch to invoke the
+    /// version declared in the given JVM class.
+    pub unsafe fn call_nonvirtual_char_method(
+        &self, jvm_object: &JvmObject, jvm_class: &JvmClass, jvm_method: &JvmMethod, jvm_method_signature: &str, args: &[JvmValue]
+    ) -> Result<jchar, JvmError> {
+
+        // Attach the current native thread to the JVM.
+        let jvm_attachment = JvmAttachment::new(self.jvm);
+
+        let jvalues = jvalues_from_jvm_values(jvm_method_signature, args)?;
+
+        let result = (**jvm_attachment.jni_environment()).CallNonvirtualCharMethodA.unwrap()(
+            jvm_attachment.jni_environment(),
+            jvm_object.jvm_ptr(),
+            jvm_class.jvm_ptr(),
+            jvm_method.jvm_ptr(),
+            jvalues.as_ptr()
+        );
+
+        check_jvm_exception(jvm_attachment.jni_environment())?;
+
+        Ok(result)
+    }
+
+    /// Tries to call the given JVM instance double method, bypassing dynamic dispatch to invoke
+    /// the version declared in the given JVM class.
+    pub unsafe fn call_nonvirtual_double_method(
+        &self, jvm_object: &JvmObject, jvm_class: &JvmClass, jvm_method: &JvmMethod, jvm_method_signature: &str, args: &[JvmValue]
+    ) -> Result<jdouble, JvmError> {
+
+        // Attach the current native thread to the JVM.
+        let jvm_attachment = JvmAttachment::new(self.jvm);
+
+        let jvalues = jvalues_from_jvm_values(jvm_method_signature, args)?;
+
+        let result = (**jvm_attachment.jni_environment()).CallNonvirtualDoubleMethodA.unwrap()(
+            jvm_attachment.jni_environment(),
+            jvm_object.jvm_ptr(),
+            jvm_class.jvm_ptr(),
+            jvm_method.jvm_ptr(),
+            jvalues.as_ptr()
+        );
+
+        check_jvm_exception(jvm_attachment.jni_environment())?;
+
+        Ok(result)
+    }
+
+    /// Tries to call the given JVM instance float method, bypassing dynamic dispatch to invoke the
+    /// version declared in the given JVM class.
+    pub unsafe fn call_nonvirtual_float_method(
+        &self, jvm_object: &JvmObject, jvm_class: &JvmClass, jvm_method: &JvmMethod, jvm_method_signature: &str, args: &[JvmValue]
+    ) -> Result<jfloat, JvmError> {
+
+        // Attach the current native thread to the JVM.
+        let jvm_attachment = JvmAttachment::new(self.jvm);
+
+        let jvalues = jvalues_from_jvm_values(jvm_method_signature, args)?;
+
+        let result = (**jvm_attachment.jni_environment()).CallNonvirtualFloatMethodA.unwrap()(
+            jvm_attachment.jni_environment(),
+            jvm_object.jvm_ptr(),
+            jvm_class.jvm_ptr(),
+            jvm_method.jvm_ptr(),
+            jvalues.as_ptr()
+        );
+
+        check_jvm_exception(jvm_attachment.jni_environment())?;
+
+        Ok(result)
+    }
+
+    /// Tries to call the given JVM instance int method, bypassing dynamic dispatch to invoke the
+    /// version declared in the given JVM class.
+    pub unsafe fn call_nonvirtual_int_method(
+        &self, jvm_object: &JvmObject, jvm_class: &JvmClass, jvm_method: &JvmMethod, jvm_method_signature: &str, args: &[JvmValue]
+    ) -> Result<jint, JvmError> {
+
+        // Attach the current native thread to the JVM.
+        let jvm_attachment = JvmAttachment::new(self.jvm);
+
+        let jvalues = jvalues_from_jvm_values(jvm_method_signature, args)?;
+
+        let result = (**jvm_attachment.jni_environment()).CallNonvirtualIntMethodA.unwrap()(
+            jvm_attachment.jni_environment(),
+            jvm_object.jvm_ptr(),
+            jvm_class.jvm_ptr(),
+            jvm_method.jvm_ptr(),
+            jvalues.as_ptr()
+        );
+
+        check_jvm_exception(jvm_attachment.jni_environment())?;
+
+        Ok(result)
+    }
+
+    /// Tries to call the given JVM instance long method, bypassing dynamic dispatch to invoke the
+    /// version declared in the given JVM class.
+    pub unsafe fn call_nonvirtual_long_method(
+        &self, jvm_object: &JvmObject, jvm_class: &JvmClass, jvm_method: &JvmMethod, jvm_method_signature: &str, args: &[JvmValue]
+    ) -> Result<jlong, JvmError> {
+
+        // Attach the current native thread to the JVM.
+        let jvm_attachment = JvmAttachment::new(self.jvm);
+
+        let jvalues = jvalues_from_jvm_values(jvm_method_signature, args)?;
+
+        let result = (**jvm_attachment.jni_environment()).CallNonvirtualLongMethodA.unwrap()(
             jvm_attachment.jni_environment(),
+            jvm_object.jvm_ptr(),
             jvm_class.jvm_ptr(),
             jvm_method.jvm_ptr(),
-            args
+            jvalues.as_ptr()
         );
 
-        print_and_panic_on_jvm_exception(jvm_attachment.jni_environment());
+        check_jvm_exception(jvm_attachment.jni_environment())?;
 
-        result
+        Ok(result)
     }
 
+    /// Tries to call the given JVM instance object method, bypassing dynamic dispatch to invoke
+    /// the version declared in the given JVM class.
+    pub unsafe fn call_nonvirtual_object_method(
+        &self, jvm_object: &JvmObject, jvm_class: &JvmClass, jvm_method: &JvmMethod, jvm_method_signature: &str, args: &[JvmValue]
+    ) -> Result<Option<JvmObject>, JvmError> {
+
+        // Attach the current native thread to the JVM.
+        let jvm_attachment = JvmAttachment::new(self.jvm);
+
+        let jvalues = jvalues_from_jvm_values(jvm_method_signature, args)?;
+
+        let result = (**jvm_attachment.jni_environment()).CallNonvirtualObjectMethodA.unwrap()(
+            jvm_attachment.jni_environment(),
+            jvm_object.jvm_ptr(),
+            jvm_class.jvm_ptr(),
+            jvm_method.jvm_ptr(),
+            jvalues.as_ptr()
+        );
+
+        check_jvm_exception(jvm_attachment.jni_environment())?;
+
+        Ok(JvmObject::from_jvm_ptr(self, result))
+    }
+
+    /// Tries to call the given JVM instance short method, bypassing dynamic dispatch to invoke the
+    /// version declared in the given JVM class.
+    pub unsafe fn call_nonvirtual_short_method(
+        &self, jvm_object: &JvmObject, jvm_class: &JvmClass, jvm_method: &JvmMethod, jvm_method_signature: &str, args: &[JvmValue]
+    ) -> Result<jshort, JvmError> {
+
+        // Attach the current native thread to the JVM.
+        let jvm_attachment = JvmAttachment::new(self.jvm);
+
+        let jvalues = jvalues_from_jvm_values(jvm_method_signature, args)?;
+
+        let result = (**jvm_attachment.jni_environment()).CallNonvirtualShortMethodA.unwrap()(
+            jvm_attachment.jni_environment(),
+            jvm_object.jvm_ptr(),
+            jvm_class.jvm_ptr(),
+            jvm_method.jvm_ptr(),
+            jvalues.as_ptr()
+        );
+
+        check_jvm_exception(jvm_attachment.jni_environment())?;
+
+        Ok(result)
+    }
+
+    /// Tries to call the given JVM instance void method, bypassing dynamic dispatch to invoke the
+    /// version declared in the given JVM class.
+    pub unsafe fn call_nonvirtual_void_method(
+        &self, jvm_object: &JvmObject, jvm_class: &JvmClass, jvm_method: &JvmMethod, jvm_method_signature: &str, args: &[JvmValue]
+    ) -> Result<(), JvmError> {
+
+        // Attach the current native thread to the JVM.
+        let jvm_attachment = JvmAttachment::new(self.jvm);
+
+        let jvalues = jvalues_from_jvm_values(jvm_method_signature, args)?;
+
+        (**jvm_attachment.jni_environment()).CallNonvirtualVoidMethodA.unwrap()(
+            jvm_attachment.jni_environment(),
+            jvm_object.jvm_ptr(),
+            jvm_class.jvm_ptr(),
+            jvm_method.jvm_ptr(),
+            jvalues.as_ptr()
+        );
+
+        check_jvm_exception(jvm_attachment.jni_environment())
+    }
+
+
+
     // TODO: call_static_byte_method()
 
     // TODO: call_static_char_method()
@@ -283,71 +1139,96 @@ impl Jvm {
 
     // TODO: call_static_float_method()
 
+    /// Tries to call the given JVM static boolean method in the given JVM class.
+    pub unsafe fn call_static_boolean_method(
+        &self, jvm_class: &JvmClass, jvm_method: &JvmMethod, jvm_method_signature: &str, args: &[JvmValue]
+    ) -> Result<jboolean, JvmError> {
+
+        // Attach the current native thread to the JVM.
+        let jvm_attachment = JvmAttachment::new(self.jvm);
+
+        let jvalues = jvalues_from_jvm_values(jvm_method_signature, args)?;
+
+        let result = (**jvm_attachment.jni_environment()).CallStaticBooleanMethodA.unwrap()(
+            jvm_attachment.jni_environment(),
+            jvm_class.jvm_ptr(),
+            jvm_method.jvm_ptr(),
+            jvalues.as_ptr()
+        );
+
+        check_jvm_exception(jvm_attachment.jni_environment())?;
+
+        Ok(result)
+    }
+
     /// Tries to call the given JVM static int method in the given JVM class.
-    /// Currently panics if a JVM exception occurs.
     pub unsafe fn call_static_int_method(
-        &self, jvm_class: &JvmClass, jvm_method: &JvmMethod, args: *const jvalue
-    ) -> jint {
+        &self, jvm_class: &JvmClass, jvm_method: &JvmMethod, jvm_method_signature: &str, args: &[JvmValue]
+    ) -> Result<jint, JvmError> {
 
         // Attach the current native thread to the JVM.
         let jvm_attachment = JvmAttachment::new(self.jvm);
 
+        let jvalues = jvalues_from_jvm_values(jvm_method_signature, args)?;
+
         let result = (**jvm_attachment.jni_environment()).CallStaticIntMethodA.unwrap()(
             jvm_attachment.jni_environment(),
             jvm_class.jvm_ptr(),
             jvm_method.jvm_ptr(),
-            args
+            jvalues.as_ptr()
         );
 
-        print_and_panic_on_jvm_exception(jvm_attachment.jni_environment());
+        check_jvm_exception(jvm_attachment.jni_environment())?;
 
-        result
+        Ok(result)
     }
 
     // TODO: call_static_long_method()
 
     ///
     pub unsafe fn call_static_object_method(
-        &self, jvm_class: &JvmClass, jvm_method: &JvmMethod, args: *const jvalue
-    ) -> Option<JvmObject> {
+        &self, jvm_class: &JvmClass, jvm_method: &JvmMethod, jvm_method_signature: &str, args: &[JvmValue]
+    ) -> Result<Option<JvmObject>, JvmError> {
 
         // Attach the current native thread to the JVM.
         let jvm_attachment = JvmAttachment::new(self.jvm);
 
+        let jvalues = jvalues_from_jvm_values(jvm_method_signature, args)?;
+
         let result = (**jvm_attachment.jni_environment()).CallStaticObjectMethodA.unwrap()(
             jvm_attachment.jni_environment(),
             jvm_class.jvm_ptr(),
             jvm_method.jvm_ptr(),
-            args
+            jvalues.as_ptr()
         );
 
-        print_and_panic_on_jvm_exception(jvm_attachment.jni_environment());
+        check_jvm_exception(jvm_attachment.jni_environment())?;
 
-        JvmObject::from_jvm_ptr(self, result)
+        Ok(JvmObject::from_jvm_ptr(self, result))
     }
 
     /// Tries to call the given JVM static void method in the given JVM class.
-    /// Currently panics if a JVM exception occurs.
     pub unsafe fn call_static_void_method(
-        &self, jvm_class: &JvmClass, jvm_method: &JvmMethod, args: *const jvalue
-    ) {
+        &self, jvm_class: &JvmClass, jvm_method: &JvmMethod, jvm_method_signature: &str, args: &[JvmValue]
+    ) -> Result<(), JvmError> {
 
         // Attach the current native thread to the JVM.
         let jvm_attachment = JvmAttachment::new(self.jvm);
 
+        let jvalues = jvalues_from_jvm_values(jvm_method_signature, args)?;
+
         (**jvm_attachment.jni_environment()).CallStaticVoidMethodA.unwrap()(
             jvm_attachment.jni_environment(),
             jvm_class.jvm_ptr(),
             jvm_method.jvm_ptr(),
-            args
+            jvalues.as_ptr()
         );
 
-        print_and_panic_on_jvm_exception(jvm_attachment.jni_environment());
-
+        check_jvm_exception(jvm_attachment.jni_environment())
     }
 
     /// Tries to resolve the JVM class with the given name.
-    pub unsafe fn get_class(&self, jvm_class_name: &str) -> Option<JvmClass> {
+    pub unsafe fn get_class(&self, jvm_class_name: &str) -> Result<JvmClass, JvmError> {
 
         // Attach the current native thread to the JVM.
         let jvm_attachment = JvmAttachment::new(self.jvm);
@@ -360,16 +1241,65 @@ impl Jvm {
                 jvm_class_name_cstring.as_ptr()
             );
 
-        // Print any JVM exception.
-        print_jvm_exception(jvm_attachment.jni_environment());
+        if jvm_class_ptr.is_null() {
+
+            // `FindClass` leaves a `ClassNotFoundException`/`NoClassDefFoundError` pending; clear
+            // it and report the more specific `ClassNotFound` instead.
+            take_jvm_exception(jvm_attachment.jni_environment());
+
+            return Err(JvmError::ClassNotFound(jvm_class_name.to_string()));
+        }
+
+        check_jvm_exception(jvm_attachment.jni_environment())?;
 
         JvmClass::from_jvm_ptr(self, jvm_class_ptr)
+            .ok_or_else(|| JvmError::ClassNotFound(jvm_class_name.to_string()))
     }
 
     /// Tries to resolve the JVM constructor with the given signature in the given JVM class.
-    pub unsafe fn get_constructor(&self, jvm_class: &JvmClass, jvm_method_signature: &str) -> Option<JvmMethod> {
+    pub unsafe fn get_constructor(
+        &self, jvm_class: &JvmClass, jvm_method_signature: &str
+    ) -> Result<JvmMethod, JvmError> {
 
         JvmMethod::get_method(self, jvm_class, "<init>", jvm_method_signature)
+            .ok_or_else(|| JvmError::MethodNotFound(format!("<init>{}", jvm_method_signature)))
+    }
+
+    /// Registers Rust functions as Java `native` methods of the given JVM class.
+    pub unsafe fn register_natives(&self, jvm_class: &JvmClass, methods: &[NativeMethod]) -> Result<(), JvmError> {
+
+        // Attach the current native thread to the JVM.
+        let jvm_attachment = JvmAttachment::new(self.jvm);
+
+        // Keep the `CString`s backing the `JNINativeMethod`s alive for the duration of the call.
+        let name_cstrings : Vec<CString> =
+            methods.iter().map(|method| CString::new(method.name).unwrap()).collect();
+        let signature_cstrings : Vec<CString> =
+            methods.iter().map(|method| CString::new(method.signature).unwrap()).collect();
+
+        let jni_native_methods : Vec<JNINativeMethod> = methods.iter().enumerate().map(|(index, method)| {
+            JNINativeMethod {
+                name: name_cstrings[index].as_ptr() as *mut i8,
+                signature: signature_cstrings[index].as_ptr() as *mut i8,
+                fnPtr: method.function_pointer,
+            }
+        }).collect();
+
+        let result = (**jvm_attachment.jni_environment()).RegisterNatives.unwrap()(
+            jvm_attachment.jni_environment(),
+            jvm_class.jvm_ptr(),
+            jni_native_methods.as_ptr(),
+            jni_native_methods.len() as jint
+        );
+
+        check_jvm_exception(jvm_attachment.jni_environment())?;
+
+        if result != JNI_OK {
+            let method_names = methods.iter().map(|method| method.name).collect::<Vec<_>>().join(", ");
+            return Err(JvmError::MethodNotFound(method_names));
+        }
+
+        Ok(())
     }
 }
 